@@ -34,18 +34,108 @@
 //! }
 //! ```
 //!
+//! Variants with named fields, or more than one unnamed field, are serialized as a single JSON
+//! object. Named fields use their identifier as the JSON key unless overridden with `#[key(...)]`;
+//! every field of a multi-unnamed-field variant must specify `#[key(...)]` explicitly:
+//! ```
+//! #[derive(axum_enum_response::EnumIntoResponse)]
+//! enum ErrorResponse {
+//!     #[status_code(NOT_FOUND)]
+//!     NotFound { resource: &'static str, id: u64 }, // 404, body = {"resource": STRING, "id": NUMBER}
+//!     #[status_code(CONFLICT)]
+//!     Conflict(#[key("resource")] &'static str, #[key("id")] u64), // 409, body = {"resource": STRING, "id": NUMBER}
+//! }
+//! ```
+//!
+//! You can attach extra headers to a variant's response with repeated `#[header(...)]` attributes:
+//! ```
+//! #[derive(axum_enum_response::EnumIntoResponse)]
+//! enum ErrorResponse {
+//!     #[status_code(UNAUTHORIZED)]
+//!     #[header("WWW-Authenticate" => "Bearer")]
+//!     Unauthorized, // 401, empty body, WWW-Authenticate: Bearer
+//! }
+//! ```
+//!
+//! With the `negotiate` feature enabled, an enum-level `#[negotiate(json, msgpack, cbor)]` attribute
+//! generates an extra `into_response_negotiated` method that picks its encoder from the request's
+//! `Accept` header instead of always using JSON (the plain `IntoResponse` impl stays JSON-only):
+//! ```ignore
+//! #[derive(axum_enum_response::EnumIntoResponse)]
+//! #[negotiate(json, msgpack, cbor)]
+//! enum ErrorResponse {
+//!     #[status_code(NOT_FOUND)]
+//!     #[body("not found")]
+//!     NotFound,
+//! }
+//! ```
+//!
+//! With the `utoipa` feature enabled, an enum-level `#[utoipa]` attribute additionally generates a
+//! `utoipa::IntoResponses` impl, with one entry per status code (variants sharing a status code
+//! produce a single entry, taken from the last of them), using an
+//! optional `#[response(description = "...")]` for the docs. `#[utoipa]` is opt-in per enum so that
+//! enabling the crate feature doesn't force every `EnumIntoResponse` type in the dependency graph to
+//! implement `utoipa::ToSchema` on its fields:
+//! ```ignore
+//! #[derive(axum_enum_response::EnumIntoResponse)]
+//! #[utoipa]
+//! enum ErrorResponse {
+//!     #[status_code(NOT_FOUND)]
+//!     #[response(description = "The resource was not found")]
+//!     #[body("not found")]
+//!     NotFound,
+//! }
+//! ```
+//!
+//! An enum-level `#[error_impl]` attribute additionally generates `std::fmt::Display` and
+//! `std::error::Error` impls. Each variant's display text comes from its `#[message(...)]`
+//! attribute if present, otherwise its `#[body(...)]` text, otherwise its inner value's `Display`
+//! (or the variant's name, if it has neither). `source()` returns the inner value for `#[from]`
+//! variants and `None` for everything else. `#[message(...)]` can also stand in for `#[body(...)]`
+//! on a field-less variant, serializing as `{"message": "..."}`:
+//! ```
+//! #[derive(Debug, axum_enum_response::EnumIntoResponse)]
+//! #[error_impl]
+//! enum ErrorResponse {
+//!     #[status_code(INTERNAL_SERVER_ERROR)]
+//!     #[message("something went wrong")]
+//!     InternalServerError, // 500, body = {"message": "something went wrong"}
+//!     #[status_code(INTERNAL_SERVER_ERROR)]
+//!     FromUtf8Error(#[from] std::string::FromUtf8Error), // source() = Some(inner)
+//! }
+//! ```
+//!
 
 #![warn(clippy::pedantic)]
 
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
-	parse::Parse, parse_macro_input, Attribute, Data, DeriveInput, Error, Ident, LitStr, Meta, Result, Token, Type,
+	parse::Parse, parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Ident, LitStr, Meta, Result,
+	Token, Type,
 };
 
 type TokenStream2 = proc_macro2::TokenStream;
 
-#[proc_macro_derive(EnumIntoResponse, attributes(status_code, body, key, from))]
+#[cfg_attr(
+	all(not(feature = "utoipa"), not(feature = "negotiate")),
+	proc_macro_derive(EnumIntoResponse, attributes(status_code, body, key, from, header, message, error_impl))
+)]
+#[cfg_attr(
+	all(feature = "utoipa", not(feature = "negotiate")),
+	proc_macro_derive(EnumIntoResponse, attributes(status_code, body, key, from, header, response, utoipa, message, error_impl))
+)]
+#[cfg_attr(
+	all(not(feature = "utoipa"), feature = "negotiate"),
+	proc_macro_derive(EnumIntoResponse, attributes(status_code, body, key, from, header, negotiate, message, error_impl))
+)]
+#[cfg_attr(
+	all(feature = "utoipa", feature = "negotiate"),
+	proc_macro_derive(
+		EnumIntoResponse,
+		attributes(status_code, body, key, from, header, response, utoipa, negotiate, message, error_impl)
+	)
+)]
 pub fn enum_into_response(input: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
 	match impl_enum_into_response(input) {
@@ -63,43 +153,236 @@ fn impl_enum_into_response(input: DeriveInput) -> Result<TokenStream> {
 		));
 	};
 
-	let (match_branches, impls) = data_enum.variants.into_iter().map(|variant| {
+	let negotiate_formats = parse_negotiate_attribute(&input.attrs)?;
+	let has_error_impl = parse_error_impl_attribute(&input.attrs)?;
+
+	#[cfg(feature = "utoipa")]
+	let has_utoipa_impl = parse_utoipa_attribute(&input.attrs)?;
+	#[cfg(not(feature = "utoipa"))]
+	let has_utoipa_impl = false;
+
+	let (match_branches, impls, responses, negotiated_arms, error_arms) = data_enum.variants.into_iter().map(|variant| {
 		let ident = &variant.ident;
 		let field_attributes = parse_field_attributes(&variant.fields)?;
-		let VariantAttributes { status_code, body } = parse_attributes(ident, &variant.attrs)?;
+		let VariantAttributes { status_code, body, response_description, headers, message } = parse_attributes(ident, &variant.attrs)?;
+
+		let body = body.or_else(|| {
+			message.clone().map(|message| BodyAttribute { key: Some("message".to_string()), value: message })
+		});
 
-		let match_branches = if let Some(FieldAttributes { key, from_ty }) = &field_attributes {
-			if from_ty.is_some() {
-				if let Some(key) = key {
-					quote! {
-						#enum_name::#ident(v) => (::axum::http::StatusCode::#status_code, Some(::axum::Json(::std::collections::HashMap::from([(#key, v.to_string())])).into_response())),
+		let headers_tokens = if headers.is_empty() {
+			quote! { ::axum::http::HeaderMap::new() }
+		} else {
+			let entries = headers.iter().map(|HeaderAttribute { name, value }| {
+				quote! {
+					(::axum::http::HeaderName::from_static(#name), ::axum::http::HeaderValue::from_static(#value)),
+				}
+			});
+			quote! {
+				::axum::http::HeaderMap::from_iter([
+					#( #entries )*
+				])
+			}
+		};
+
+		let (pattern, body_expr): (TokenStream2, Option<TokenStream2>) = match &field_attributes {
+			Some(Fields::Single(FieldAttributes { key, from_ty, .. })) => {
+				let body_expr = if from_ty.is_some() {
+					if let Some(key) = key {
+						quote! { ::std::collections::HashMap::from([(#key, v.to_string())]) }
+					} else {
+						quote! { ::std::collections::HashMap::from([("error", v.to_string())]) }
 					}
+				} else if let Some(key) = key {
+					quote! { ::std::collections::HashMap::from([(#key, v)]) }
 				} else {
-					quote! {
-						#enum_name::#ident(v) => (::axum::http::StatusCode::#status_code, Some(::axum::Json(::std::collections::HashMap::from([("error", v.to_string())])).into_response())),
+					quote! { v }
+				};
+
+				(quote! { #enum_name::#ident(v) }, Some(body_expr))
+			}
+
+			Some(Fields::Multi(MultiFields { named, fields })) => {
+				let bindings: Vec<_> = fields.iter().map(|f| &f.binding).collect();
+				let keys: Vec<_> = fields.iter().map(|f| &f.key).collect();
+				let fields_pattern = if *named {
+					quote! { { #( #bindings ),* } }
+				} else {
+					quote! { ( #( #bindings ),* ) }
+				};
+
+				(quote! { #enum_name::#ident #fields_pattern }, Some(quote! { ::serde_json::json!({ #( #keys: #bindings ),* }) }))
+			}
+
+			None => {
+				if let Some(BodyAttribute { key, value }) = &body {
+					let key = key.clone().unwrap_or_else(|| "error".to_string());
+					let value = value.clone();
+					(quote! { #enum_name::#ident }, Some(quote! { ::std::collections::HashMap::from([(#key, #value)]) }))
+				} else {
+					(quote! { #enum_name::#ident }, None)
+				}
+			}
+		};
+
+		let match_branches = if let Some(body_expr) = &body_expr {
+			quote! {
+				#pattern => (::axum::http::StatusCode::#status_code, #headers_tokens, Some(::axum::Json(#body_expr).into_response())),
+			}
+		} else {
+			quote! {
+				#pattern => (::axum::http::StatusCode::#status_code, #headers_tokens, None),
+			}
+		};
+
+		let error_arm = if has_error_impl {
+			let wildcard_pattern = wildcard_pattern(&enum_name, ident, &field_attributes);
+
+			let display_arm = if let Some(message) = &message {
+				quote! { #wildcard_pattern => ::std::write!(f, "{}", #message), }
+			} else if let Some(BodyAttribute { value, .. }) = &body {
+				quote! { #wildcard_pattern => ::std::write!(f, "{}", #value), }
+			} else if matches!(&field_attributes, Some(Fields::Single(_))) {
+				quote! { #pattern => ::std::write!(f, "{v}"), }
+			} else {
+				let name = ident.to_string();
+				quote! { #wildcard_pattern => ::std::write!(f, "{}", #name), }
+			};
+
+			let source_arm = if let Some(Fields::Single(FieldAttributes { from_ty: Some(_), .. })) = &field_attributes {
+				quote! { #pattern => ::std::option::Option::Some(v), }
+			} else {
+				quote! { #wildcard_pattern => ::std::option::Option::None, }
+			};
+
+			Some((display_arm, source_arm))
+		} else {
+			None
+		};
+
+		#[cfg(feature = "negotiate")]
+		let negotiated_arm = if let Some(body_expr) = &body_expr {
+			quote! {
+				#pattern => (::axum::http::StatusCode::#status_code, #headers_tokens, Some(::serde_json::to_value(#body_expr).unwrap_or(::serde_json::Value::Null))),
+			}
+		} else {
+			quote! {
+				#pattern => (::axum::http::StatusCode::#status_code, #headers_tokens, None),
+			}
+		};
+		#[cfg(not(feature = "negotiate"))]
+		let negotiated_arm = quote! {};
+
+		#[cfg(feature = "utoipa")]
+		let response_entry = if has_utoipa_impl {
+			let description = response_description.unwrap_or_default();
+
+			let string_prop = quote! {
+				::utoipa::openapi::RefOr::T({
+					let schema: ::utoipa::openapi::schema::Schema = ::utoipa::openapi::ObjectBuilder::new()
+						.schema_type(::utoipa::openapi::schema::SchemaType::Type(::utoipa::openapi::schema::Type::String))
+						.build()
+						.into();
+					schema
+				})
+			};
+
+			let content = match &field_attributes {
+				Some(Fields::Single(FieldAttributes { key, from_ty, ty })) => {
+					if from_ty.is_some() {
+						let key = key.clone().unwrap_or_else(|| quote! { "error" });
+						Some(quote! {
+							::utoipa::openapi::RefOr::T({
+								let schema: ::utoipa::openapi::schema::Schema = ::utoipa::openapi::ObjectBuilder::new()
+									.property(#key, #string_prop)
+									.required(#key)
+									.build()
+									.into();
+								schema
+							})
+						})
+					} else if let Some(key) = key {
+						Some(quote! {
+							::utoipa::openapi::RefOr::T({
+								let schema: ::utoipa::openapi::schema::Schema = ::utoipa::openapi::ObjectBuilder::new()
+									.property(#key, <#ty as ::utoipa::ToSchema>::schema().1)
+									.required(#key)
+									.build()
+									.into();
+								schema
+							})
+						})
+					} else {
+						Some(quote! { <#ty as ::utoipa::ToSchema>::schema().1 })
+					}
+				}
+
+				Some(Fields::Multi(MultiFields { fields, .. })) => {
+					let properties = fields.iter().map(|MultiField { key, ty, .. }| {
+						quote! {
+							.property(#key, <#ty as ::utoipa::ToSchema>::schema().1)
+							.required(#key)
+						}
+					});
+					Some(quote! {
+						::utoipa::openapi::RefOr::T({
+							let schema: ::utoipa::openapi::schema::Schema = ::utoipa::openapi::ObjectBuilder::new()
+								#( #properties )*
+								.build()
+								.into();
+							schema
+						})
+					})
+				}
+
+				None => {
+					if let Some(BodyAttribute { key, .. }) = &body {
+						let key = key.clone().unwrap_or_else(|| "error".to_string());
+						Some(quote! {
+							::utoipa::openapi::RefOr::T({
+								let schema: ::utoipa::openapi::schema::Schema = ::utoipa::openapi::ObjectBuilder::new()
+									.property(#key, #string_prop)
+									.required(#key)
+									.build()
+									.into();
+								schema
+							})
+						})
+					} else {
+						None
 					}
 				}
-			} else if let Some(key) = key {
+			};
+
+			let response_builder = if let Some(content) = content {
 				quote! {
-					#enum_name::#ident(v) => (::axum::http::StatusCode::#status_code, Some(::axum::Json(::std::collections::HashMap::from([(#key, v)])).into_response())),
+					::utoipa::openapi::ResponseBuilder::new()
+						.description(#description)
+						.content("application/json", ::utoipa::openapi::ContentBuilder::new().schema(Some(#content)).build())
+						.build()
 				}
 			} else {
 				quote! {
-					#enum_name::#ident(v) => (::axum::http::StatusCode::#status_code, Some(::axum::Json(v).into_response())),
+					::utoipa::openapi::ResponseBuilder::new()
+						.description(#description)
+						.build()
 				}
-			}
-		} else if let Some(BodyAttribute { key, value }) = body {
-			let key = key.unwrap_or_else(|| "error".to_string());
+			};
+
 			quote! {
-				#enum_name::#ident => (::axum::http::StatusCode::#status_code, Some(::axum::Json(::std::collections::HashMap::from([(#key, #value)])).into_response())),
+				.response(::axum::http::StatusCode::#status_code.as_u16().to_string(), #response_builder)
 			}
 		} else {
-			quote! {
-				#enum_name::#ident => (::axum::http::StatusCode::#status_code, None),
-			}
+			let _ = &response_description;
+			quote! {}
+		};
+		#[cfg(not(feature = "utoipa"))]
+		let response_entry = {
+			let _ = &response_description;
+			quote! {}
 		};
 
-		Result::Ok((match_branches, if let Some(FieldAttributes { from_ty: Some(ty), .. }) = field_attributes {
+		Result::Ok((match_branches, if let Some(Fields::Single(FieldAttributes { from_ty: Some(ty), .. })) = field_attributes {
 			Some(quote! {
 			impl From<#ty> for #enum_name {
 				fn from(value: #ty) -> Self {
@@ -109,21 +392,28 @@ fn impl_enum_into_response(input: DeriveInput) -> Result<TokenStream> {
 			})
 		} else {
 			None
-		}))
-	}).collect::<Result<(Vec<_>, Vec<_>)>>()?;
+		}, response_entry, negotiated_arm, error_arm))
+	}).collect::<Result<(Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>)>>()?;
+
+	let negotiated_impl = negotiated_impl(&enum_name, negotiate_formats, &negotiated_arms);
+	let error_impl = error_impl(&enum_name, has_error_impl, &error_arms);
+	let utoipa_impl = utoipa_impl(&enum_name, has_utoipa_impl, &responses);
 
 	let output = quote! {
 		impl ::axum::response::IntoResponse for #enum_name {
 			fn into_response(self) -> ::axum::response::Response {
-				let (status_code, body): (::axum::http::StatusCode, Option<::axum::response::Response>) = match self {
+				let (status_code, headers, body): (::axum::http::StatusCode, ::axum::http::HeaderMap, Option<::axum::response::Response>) = match self {
 					#( #match_branches )*
 				};
 
-				let Some(body) = body else {
-					return status_code.into_response();
+				let mut response = if let Some(body) = body {
+					(status_code, body).into_response()
+				} else {
+					status_code.into_response()
 				};
 
-				(status_code, body).into_response()
+				response.headers_mut().extend(headers);
+				response
 			}
 		}
 
@@ -134,70 +424,369 @@ fn impl_enum_into_response(input: DeriveInput) -> Result<TokenStream> {
 		}
 
 		#( #impls )*
+
+		#negotiated_impl
+
+		#error_impl
+
+		#utoipa_impl
 	};
 
 	Ok(output.into())
 }
 
+fn wildcard_pattern(enum_name: &Ident, ident: &Ident, field_attributes: &Option<Fields>) -> TokenStream2 {
+	match field_attributes {
+		Some(Fields::Single(_)) => quote! { #enum_name::#ident(_) },
+		Some(Fields::Multi(MultiFields { named: true, .. })) => quote! { #enum_name::#ident { .. } },
+		Some(Fields::Multi(MultiFields { named: false, .. })) => quote! { #enum_name::#ident(..) },
+		None => quote! { #enum_name::#ident },
+	}
+}
+
+fn error_impl(enum_name: &Ident, has_error_impl: bool, error_arms: &[Option<(TokenStream2, TokenStream2)>]) -> TokenStream2 {
+	if !has_error_impl {
+		return quote! {};
+	}
+
+	let (display_arms, source_arms): (Vec<_>, Vec<_>) = error_arms
+		.iter()
+		.map(|arm| arm.as_ref().expect("all arms are populated when has_error_impl is true"))
+		.map(|(display_arm, source_arm)| (display_arm, source_arm))
+		.unzip();
+
+	quote! {
+		impl ::std::fmt::Display for #enum_name {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+				match self {
+					#( #display_arms )*
+				}
+			}
+		}
+
+		impl ::std::error::Error for #enum_name {
+			fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+				match self {
+					#( #source_arms )*
+				}
+			}
+		}
+	}
+}
+
+#[cfg(feature = "utoipa")]
+fn utoipa_impl(enum_name: &Ident, has_utoipa_impl: bool, responses: &[TokenStream2]) -> TokenStream2 {
+	if !has_utoipa_impl {
+		return quote! {};
+	}
+
+	quote! {
+		impl ::utoipa::IntoResponses for #enum_name {
+			fn responses() -> ::std::collections::BTreeMap<String, ::utoipa::openapi::RefOr<::utoipa::openapi::response::Response>> {
+				::utoipa::openapi::response::ResponsesBuilder::new()
+					#( #responses )*
+					.build()
+					.into()
+			}
+		}
+	}
+}
+
+#[cfg(not(feature = "utoipa"))]
+fn utoipa_impl(_enum_name: &Ident, _has_utoipa_impl: bool, _responses: &[TokenStream2]) -> TokenStream2 {
+	quote! {}
+}
+
+#[cfg(feature = "negotiate")]
+fn negotiated_impl(enum_name: &Ident, negotiate_formats: Option<Vec<String>>, negotiated_arms: &[TokenStream2]) -> TokenStream2 {
+	let Some(formats) = negotiate_formats else {
+		return quote! {};
+	};
+
+	let format_arms = formats.iter().map(|format| match format.as_str() {
+		"json" => quote! {
+			if accept_str.contains("application/json") {
+				let bytes = ::serde_json::to_vec(&value).unwrap_or_default();
+				return (status_code, [(::axum::http::header::CONTENT_TYPE, "application/json")], bytes).into_response();
+			}
+		},
+		"msgpack" => quote! {
+			if accept_str.contains("application/msgpack") || accept_str.contains("application/x-msgpack") {
+				let bytes = ::rmp_serde::to_vec(&value).unwrap_or_default();
+				return (status_code, [(::axum::http::header::CONTENT_TYPE, "application/msgpack")], bytes).into_response();
+			}
+		},
+		"cbor" => quote! {
+			if accept_str.contains("application/cbor") {
+				let mut bytes = Vec::new();
+				let _ = ::ciborium::into_writer(&value, &mut bytes);
+				return (status_code, [(::axum::http::header::CONTENT_TYPE, "application/cbor")], bytes).into_response();
+			}
+		},
+		_ => unreachable!("validated in NegotiateAttribute::parse"),
+	});
+
+	quote! {
+		impl #enum_name {
+			/// Serializes the response body using the encoder selected by the request's `Accept`
+			/// header, falling back to JSON when no listed format is acceptable.
+			pub fn into_response_negotiated(self, accept: &::axum::http::HeaderMap) -> ::axum::response::Response {
+				let (status_code, headers, body): (::axum::http::StatusCode, ::axum::http::HeaderMap, Option<::serde_json::Value>) = match self {
+					#( #negotiated_arms )*
+				};
+
+				let mut response = match body {
+					None => status_code.into_response(),
+					Some(value) => {
+						let accept_str = accept
+							.get(::axum::http::header::ACCEPT)
+							.and_then(|v| v.to_str().ok())
+							.unwrap_or_default();
+
+						#( #format_arms )*
+
+						let bytes = ::serde_json::to_vec(&value).unwrap_or_default();
+						(status_code, [(::axum::http::header::CONTENT_TYPE, "application/json")], bytes).into_response()
+					}
+				};
+
+				response.headers_mut().extend(headers);
+				response
+			}
+		}
+	}
+}
+
+#[cfg(not(feature = "negotiate"))]
+fn negotiated_impl(_enum_name: &Ident, _negotiate_formats: Option<Vec<String>>, _negotiated_arms: &[TokenStream2]) -> TokenStream2 {
+	quote! {}
+}
+
 struct FieldAttributes {
 	key: Option<TokenStream2>,
 	from_ty: Option<Type>,
+	ty: Type,
 }
 
-fn parse_field_attributes(fields: &syn::Fields) -> Result<Option<FieldAttributes>> {
-	let mut fields = fields.iter();
-	let Some(field) = fields.next() else {
-		return Ok(None);
-	};
+enum Fields {
+	Single(FieldAttributes),
+	Multi(MultiFields),
+}
 
-	if field.ident.is_some() {
-		return Err(syn::Error::new_spanned(
-			field,
-			"EnumIntoResponse only supports unnamed fields.",
-		));
-	}
+struct MultiFields {
+	named: bool,
+	fields: Vec<MultiField>,
+}
 
-	if let Some(field) = fields.next() {
-		return Err(syn::Error::new_spanned(
-			field,
-			"EnumIntoResponse only supports up to one unnamed field.",
-		));
-	}
+struct MultiField {
+	binding: Ident,
+	key: String,
+	ty: Type,
+}
 
-	let mut key = None;
-	let mut from_ty = None;
+fn parse_field_attributes(fields: &syn::Fields) -> Result<Option<Fields>> {
+	match fields {
+		syn::Fields::Unit => Ok(None),
 
-	for attribute in &field.attrs {
+		syn::Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+			let field = unnamed.unnamed.first().expect("checked len == 1 above");
+
+			let mut key = None;
+			let mut from_ty = None;
+
+			for attribute in &field.attrs {
+				let Some(iden) = attribute.path().get_ident() else {
+					return Err(Error::new_spanned(attribute, "You must name attributes"));
+				};
+
+				match iden.to_string().as_str() {
+					"key" => {
+						if let Meta::List(list) = &attribute.meta {
+							let tokens = &list.tokens;
+							key = Some(quote! {
+								#tokens
+							});
+						} else {
+							return Err(Error::new_spanned(attribute, "'key' attribute value must be a string"));
+						}
+					}
+
+					"from" => {
+						from_ty = Some(field.ty.clone());
+					}
+
+					_ => {}
+				}
+			}
+
+			Ok(Some(Fields::Single(FieldAttributes { key, from_ty, ty: field.ty.clone() })))
+		}
+
+		syn::Fields::Unnamed(unnamed) => {
+			let fields = unnamed
+				.unnamed
+				.iter()
+				.enumerate()
+				.map(|(index, field)| {
+					let key = parse_key_attribute(&field.attrs)?.ok_or_else(|| {
+						Error::new_spanned(
+							field,
+							"every field of a multi-field variant must specify a '#[key(\"...\")]'",
+						)
+					})?;
+
+					Ok(MultiField {
+						binding: Ident::new(&format!("field_{index}"), field.span()),
+						key,
+						ty: field.ty.clone(),
+					})
+				})
+				.collect::<Result<Vec<_>>>()?;
+
+			Ok(Some(Fields::Multi(MultiFields { named: false, fields })))
+		}
+
+		syn::Fields::Named(named) => {
+			let fields = named
+				.named
+				.iter()
+				.map(|field| {
+					let ident = field.ident.clone().expect("named field always has an ident");
+					let key = parse_key_attribute(&field.attrs)?.unwrap_or_else(|| ident.to_string());
+
+					Ok(MultiField { binding: ident, key, ty: field.ty.clone() })
+				})
+				.collect::<Result<Vec<_>>>()?;
+
+			Ok(Some(Fields::Multi(MultiFields { named: true, fields })))
+		}
+	}
+}
+
+fn parse_key_attribute(attrs: &[Attribute]) -> Result<Option<String>> {
+	for attribute in attrs {
 		let Some(iden) = attribute.path().get_ident() else {
 			return Err(Error::new_spanned(attribute, "You must name attributes"));
 		};
 
-		match iden.to_string().as_str() {
-			"key" => {
-				if let Meta::List(list) = &attribute.meta {
-					let tokens = &list.tokens;
-					key = Some(quote! {
-						#tokens
-					});
+		if iden == "key" {
+			return Ok(Some(attribute.meta.require_list()?.parse_args::<LitStr>()?.value()));
+		}
+	}
+
+	Ok(None)
+}
+
+struct NegotiateAttribute {
+	formats: Vec<String>,
+}
+
+impl Parse for NegotiateAttribute {
+	fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+		let idents = syn::punctuated::Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+		let formats = idents
+			.iter()
+			.map(|ident| {
+				let format = ident.to_string();
+				if matches!(format.as_str(), "json" | "msgpack" | "cbor") {
+					Ok(format)
 				} else {
-					return Err(Error::new_spanned(attribute, "'key' attribute value must be a string"));
+					Err(Error::new_spanned(ident, format!("'{format}' is not a supported negotiation format")))
 				}
-			}
+			})
+			.collect::<Result<Vec<_>>>()?;
 
-			"from" => {
-				from_ty = Some(field.ty.clone());
-			}
+		Ok(Self { formats })
+	}
+}
 
-			_ => {}
+fn parse_negotiate_attribute(attrs: &[Attribute]) -> Result<Option<Vec<String>>> {
+	for attribute in attrs {
+		let Some(iden) = attribute.path().get_ident() else {
+			continue;
+		};
+
+		if iden == "negotiate" {
+			return Ok(Some(attribute.meta.require_list()?.parse_args::<NegotiateAttribute>()?.formats));
 		}
 	}
 
-	Ok(Some(FieldAttributes { key, from_ty }))
+	Ok(None)
 }
 
 struct VariantAttributes {
 	status_code: TokenStream2,
 	body: Option<BodyAttribute>,
+	response_description: Option<String>,
+	headers: Vec<HeaderAttribute>,
+	message: Option<String>,
+}
+
+struct HeaderAttribute {
+	name: String,
+	value: String,
+}
+
+impl Parse for HeaderAttribute {
+	fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+		let name_lit = input.parse::<LitStr>()?;
+		input.parse::<Token![=>]>()?;
+		let value_lit = input.parse::<LitStr>()?;
+
+		let name = name_lit.value();
+		if !is_valid_header_name(&name) {
+			return Err(Error::new_spanned(&name_lit, format!("'{name}' is not a valid header name")));
+		}
+		// `HeaderName::from_static` panics at runtime on any uppercase byte, so the literal must
+		// already be lowercase by the time it reaches the generated `from_static` call.
+		let name = name.to_lowercase();
+
+		let value = value_lit.value();
+		if !is_valid_header_value(&value) {
+			return Err(Error::new_spanned(&value_lit, format!("'{value}' is not a valid header value")));
+		}
+
+		Ok(Self { name, value })
+	}
+}
+
+fn is_valid_header_name(name: &str) -> bool {
+	!name.is_empty()
+		&& name.bytes().all(|b| {
+			matches!(b, b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z')
+		})
+}
+
+fn is_valid_header_value(value: &str) -> bool {
+	value.bytes().all(|b| b == b'\t' || (0x20..=0x7e).contains(&b))
+}
+
+struct ResponseAttribute {
+	description: Option<String>,
+}
+
+impl Parse for ResponseAttribute {
+	fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+		fn parse_entry(input: syn::parse::ParseStream) -> Result<Option<String>> {
+			let key = input.parse::<Ident>()?;
+			input.parse::<Token![=]>()?;
+			let value = input.parse::<LitStr>()?;
+
+			match key.to_string().as_str() {
+				"description" => Ok(Some(value.value())),
+				_ => Err(Error::new_spanned(key, "unknown 'response' attribute key")),
+			}
+		}
+
+		let mut description = parse_entry(input)?;
+
+		while !input.is_empty() {
+			input.parse::<Token![,]>()?;
+			description = parse_entry(input)?;
+		}
+
+		Ok(Self { description })
+	}
 }
 
 struct BodyAttribute {
@@ -239,6 +828,9 @@ fn parse_attributes(ident: &Ident, attributes: &Vec<Attribute>) -> Result<Varian
 
 	let mut status_code = None;
 	let mut body = None;
+	let mut response_description = None;
+	let mut headers = Vec::new();
+	let mut message = None;
 
 	for attribute in attributes {
 		let Some(iden) = attribute.path().get_ident() else {
@@ -254,6 +846,18 @@ fn parse_attributes(ident: &Ident, attributes: &Vec<Attribute>) -> Result<Varian
 				body = Some(attribute.meta.require_list()?.parse_args::<BodyAttribute>()?);
 			}
 
+			"response" => {
+				response_description = attribute.meta.require_list()?.parse_args::<ResponseAttribute>()?.description;
+			}
+
+			"header" => {
+				headers.push(attribute.meta.require_list()?.parse_args::<HeaderAttribute>()?);
+			}
+
+			"message" => {
+				message = Some(attribute.meta.require_list()?.parse_args::<LitStr>()?.value());
+			}
+
 			_ => {}
 		}
 	}
@@ -262,5 +866,42 @@ fn parse_attributes(ident: &Ident, attributes: &Vec<Attribute>) -> Result<Varian
 		return Err(Error::new_spanned(ident, "'status_code' attribute must be specified"));
 	};
 
-	Ok(VariantAttributes { status_code, body })
+	Ok(VariantAttributes { status_code, body, response_description, headers, message })
+}
+
+#[cfg(feature = "utoipa")]
+fn parse_utoipa_attribute(attrs: &[Attribute]) -> Result<bool> {
+	for attribute in attrs {
+		let Some(iden) = attribute.path().get_ident() else {
+			continue;
+		};
+
+		if iden == "utoipa" {
+			if !matches!(attribute.meta, Meta::Path(_)) {
+				return Err(Error::new_spanned(attribute, "'utoipa' does not take any arguments"));
+			}
+
+			return Ok(true);
+		}
+	}
+
+	Ok(false)
+}
+
+fn parse_error_impl_attribute(attrs: &[Attribute]) -> Result<bool> {
+	for attribute in attrs {
+		let Some(iden) = attribute.path().get_ident() else {
+			continue;
+		};
+
+		if iden == "error_impl" {
+			if !matches!(attribute.meta, Meta::Path(_)) {
+				return Err(Error::new_spanned(attribute, "'error_impl' does not take any arguments"));
+			}
+
+			return Ok(true);
+		}
+	}
+
+	Ok(false)
 }
@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::string::FromUtf8Error;
+
+use axum_enum_response::EnumIntoResponse;
+
+#[derive(Debug, EnumIntoResponse)]
+#[error_impl]
+enum TestResponse {
+	#[status_code(INTERNAL_SERVER_ERROR)]
+	#[message("something went wrong")]
+	InternalServerError,
+	#[status_code(INTERNAL_SERVER_ERROR)]
+	FromUtf8Error(#[from] FromUtf8Error),
+}
+
+#[test]
+fn display_and_source() {
+	{
+		let err = TestResponse::InternalServerError;
+		assert_eq!(err.to_string(), "something went wrong");
+		assert!(err.source().is_none());
+	}
+
+	{
+		let inner = String::from_utf8(vec![0xff]).unwrap_err();
+		let message = inner.to_string();
+		let err = TestResponse::FromUtf8Error(inner);
+		assert_eq!(err.to_string(), message);
+		assert!(err.source().is_some());
+	}
+}
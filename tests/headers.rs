@@ -0,0 +1,30 @@
+use axum::{http::StatusCode, response::IntoResponse};
+use axum_enum_response::EnumIntoResponse;
+
+#[derive(EnumIntoResponse)]
+enum TestResponse {
+	#[status_code(UNAUTHORIZED)]
+	#[header("WWW-Authenticate" => "Bearer")]
+	Unauthorized,
+	#[status_code(OK)]
+	#[header("Cache-Control" => "no-store")]
+	#[header("X-Custom" => "meow")]
+	#[body("hello"=>"world")]
+	Ok,
+}
+
+#[tokio::test]
+async fn headers() {
+	{
+		let res = TestResponse::Unauthorized.into_response();
+		assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+		assert_eq!(res.headers().get("WWW-Authenticate").unwrap(), "Bearer");
+	}
+
+	{
+		let res = TestResponse::Ok.into_response();
+		assert_eq!(res.status(), StatusCode::OK);
+		assert_eq!(res.headers().get("Cache-Control").unwrap(), "no-store");
+		assert_eq!(res.headers().get("X-Custom").unwrap(), "meow");
+	}
+}
@@ -0,0 +1,48 @@
+use axum::{
+	body::Body,
+	http::{Response, StatusCode},
+	response::IntoResponse,
+};
+use axum_enum_response::EnumIntoResponse;
+use futures::StreamExt;
+
+#[derive(EnumIntoResponse)]
+enum TestResponse {
+	#[status_code(NOT_FOUND)]
+	NotFound { resource: &'static str, id: u64 },
+	#[status_code(CONFLICT)]
+	Conflict(#[key("resource")] &'static str, #[key("id")] u64),
+}
+
+async fn get_body(res: Response<Body>) -> String {
+	let stream = res.into_body().into_data_stream();
+	String::from_utf8(
+		stream
+			.collect::<Vec<_>>()
+			.await
+			.into_iter()
+			.map(|v| v.unwrap())
+			.collect::<Vec<_>>()
+			.concat(),
+	)
+	.unwrap()
+}
+
+#[tokio::test]
+async fn multi_field() {
+	{
+		let res = TestResponse::NotFound { resource: "user", id: 42 }.into_response();
+		assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+		let body = get_body(res).await;
+		assert_eq!(body, "{\"id\":42,\"resource\":\"user\"}");
+	}
+
+	{
+		let res = TestResponse::Conflict("user", 42).into_response();
+		assert_eq!(res.status(), StatusCode::CONFLICT);
+
+		let body = get_body(res).await;
+		assert_eq!(body, "{\"id\":42,\"resource\":\"user\"}");
+	}
+}
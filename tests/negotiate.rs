@@ -0,0 +1,81 @@
+#![cfg(feature = "negotiate")]
+
+use axum::{
+	body::Body,
+	http::{HeaderMap, HeaderValue, Response, StatusCode},
+	response::IntoResponse,
+};
+use axum_enum_response::EnumIntoResponse;
+use futures::StreamExt;
+
+#[derive(EnumIntoResponse)]
+#[negotiate(json, msgpack, cbor)]
+enum TestResponse {
+	#[status_code(NOT_FOUND)]
+	#[body("not found")]
+	NotFound,
+	#[status_code(INTERNAL_SERVER_ERROR)]
+	InternalServerError,
+}
+
+async fn get_body(res: Response<Body>) -> Vec<u8> {
+	let stream = res.into_body().into_data_stream();
+	stream.collect::<Vec<_>>().await.into_iter().map(|v| v.unwrap()).collect::<Vec<_>>().concat()
+}
+
+fn accept(value: &str) -> HeaderMap {
+	let mut headers = HeaderMap::new();
+	headers.insert(axum::http::header::ACCEPT, HeaderValue::from_str(value).unwrap());
+	headers
+}
+
+#[tokio::test]
+async fn negotiates_json() {
+	let res = TestResponse::NotFound.into_response_negotiated(&accept("application/json"));
+	assert_eq!(res.status(), StatusCode::NOT_FOUND);
+	assert_eq!(res.headers().get("content-type").unwrap(), "application/json");
+
+	let body = get_body(res).await;
+	assert_eq!(serde_json::from_slice::<serde_json::Value>(&body).unwrap(), serde_json::json!({"error": "not found"}));
+}
+
+#[tokio::test]
+async fn negotiates_msgpack() {
+	let res = TestResponse::NotFound.into_response_negotiated(&accept("application/msgpack"));
+	assert_eq!(res.status(), StatusCode::NOT_FOUND);
+	assert_eq!(res.headers().get("content-type").unwrap(), "application/msgpack");
+
+	let body = get_body(res).await;
+	let value: serde_json::Value = rmp_serde::from_slice(&body).unwrap();
+	assert_eq!(value, serde_json::json!({"error": "not found"}));
+}
+
+#[tokio::test]
+async fn negotiates_cbor() {
+	let res = TestResponse::NotFound.into_response_negotiated(&accept("application/cbor"));
+	assert_eq!(res.status(), StatusCode::NOT_FOUND);
+	assert_eq!(res.headers().get("content-type").unwrap(), "application/cbor");
+
+	let body = get_body(res).await;
+	let value: serde_json::Value = ciborium::from_reader(body.as_slice()).unwrap();
+	assert_eq!(value, serde_json::json!({"error": "not found"}));
+}
+
+#[tokio::test]
+async fn falls_back_to_json_for_unacceptable_accept() {
+	let res = TestResponse::NotFound.into_response_negotiated(&accept("text/plain"));
+	assert_eq!(res.status(), StatusCode::NOT_FOUND);
+	assert_eq!(res.headers().get("content-type").unwrap(), "application/json");
+
+	let body = get_body(res).await;
+	assert_eq!(serde_json::from_slice::<serde_json::Value>(&body).unwrap(), serde_json::json!({"error": "not found"}));
+}
+
+#[tokio::test]
+async fn empty_body_variant_ignores_accept() {
+	let res = TestResponse::InternalServerError.into_response_negotiated(&accept("application/msgpack"));
+	assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+	let body = get_body(res).await;
+	assert!(body.is_empty());
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "utoipa")]
+
+use axum_enum_response::EnumIntoResponse;
+use utoipa::IntoResponses;
+
+#[derive(EnumIntoResponse)]
+#[utoipa]
+enum TestResponse {
+	#[status_code(NOT_FOUND)]
+	#[response(description = "The resource was not found")]
+	#[body("not found")]
+	NotFound,
+	#[status_code(INTERNAL_SERVER_ERROR)]
+	InternalServerError,
+}
+
+#[test]
+fn responses() {
+	let responses = TestResponse::responses();
+
+	let not_found = responses.get("404").unwrap();
+	assert!(matches!(not_found, utoipa::openapi::RefOr::T(response) if response.description == "The resource was not found"));
+
+	let internal_server_error = responses.get("500").unwrap();
+	assert!(matches!(internal_server_error, utoipa::openapi::RefOr::T(response) if response.description.is_empty()));
+}